@@ -0,0 +1,2 @@
+pub mod multilinear;
+pub mod sumcheck;
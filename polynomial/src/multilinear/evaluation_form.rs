@@ -1,5 +1,26 @@
 use crate::multilinear::pairing_index::index_pair;
 use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// below this many pairs, folding sequentially is faster than paying the
+/// rayon thread dispatch overhead
+#[cfg(feature = "parallel")]
+const PARALLEL_FOLD_THRESHOLD: usize = 1 << 10;
+
+/// folds a single (left, right) evaluation pair against `assignment`
+fn fold_pair<F: PrimeField>(left: F, right: F, assignment: &F) -> F {
+    match assignment {
+        a if a.is_zero() => left,
+        a if a.is_one() => right,
+        _ => {
+            // linear interpolation
+            // (1-r) * left + r * right
+            // left - r.left + r.right
+            // left - r (left - right)
+            left - *assignment * (left - right)
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 /// `MultilinearPolynomial` (Dense Evaluation Representation)
@@ -31,12 +52,44 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
         self.n_vars
     }
 
+    /// Builds the equality/selector MLE eq(x, r) = ∏_i (x_i·r_i + (1-x_i)(1-r_i))
+    /// i.e. the multilinear extension that equals 1 when the boolean point x
+    /// matches r exactly, and 0 on every other corner of the hypercube.
+    /// `r[0]` is treated as the high variable, matching `partial_evaluate`'s
+    /// variable ordering.
+    ///
+    /// computed without touching all 2^n corners individually: starting from
+    /// a single entry [1], each `r_i` doubles the buffer by replacing every
+    /// partial product `p` with the pair `(p*(1-r_i), p*r_i)`, for 2^n
+    /// multiplications total.
+    pub fn eq_mle(r: &[F]) -> Self {
+        let mut evaluations = vec![F::one()];
+
+        for r_i in r {
+            let mut next_evaluations = Vec::with_capacity(evaluations.len() * 2);
+            for p in &evaluations {
+                next_evaluations.push(*p * (F::one() - r_i));
+                next_evaluations.push(*p * r_i);
+            }
+            evaluations = next_evaluations;
+        }
+
+        Self {
+            n_vars: r.len(),
+            evaluations,
+        }
+    }
+
     /// Partially evaluate the `MultilinearPolynomial` at n consecutive variables
     /// e.g. f(a, b, c, d, e, f)
     /// we can pick a starting variable and supply n evaluation points
     /// f.partial_evaluate(1, [2, 3, 4])
     /// this partially evaluates 3 variables, starting at var b
     /// so b = 2, c = 3 and d = 4
+    ///
+    /// with the `parallel` feature enabled, rounds with at least
+    /// `PARALLEL_FOLD_THRESHOLD` pairs fold across threads via rayon, since
+    /// each output pair is independent of every other
     pub fn partial_evaluate(
         &self,
         initial_var: usize,
@@ -52,22 +105,26 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
         // pull the evaluation pairs from the boolean hypercube
         // interpolate and evaluate the straight line given by each pair at the assignment
         for (i, assignment) in assignments.iter().enumerate() {
-            let pairing_iterator = index_pair((self.n_vars - i) as u8, initial_var as u8);
-            for (i, (left_pos, right_pos)) in pairing_iterator.enumerate() {
-                let left = new_evaluations[left_pos];
-                let right = new_evaluations[right_pos];
-
-                new_evaluations[i] = match assignment {
-                    a if a.is_zero() => left,
-                    a if a.is_one() => right,
-                    _ => {
-                        // linear interpolation
-                        // (1-r) * left + r * right
-                        // left - r.left + r.right
-                        // left - r (left - right)
-                        left - *assignment * (left - right)
-                    }
-                };
+            let pairs: Vec<(usize, usize)> =
+                index_pair((self.n_vars - i) as u8, initial_var as u8).collect();
+
+            #[cfg(feature = "parallel")]
+            if pairs.len() >= PARALLEL_FOLD_THRESHOLD {
+                use rayon::prelude::*;
+
+                let folded: Vec<F> = pairs
+                    .par_iter()
+                    .map(|&(left_pos, right_pos)| {
+                        fold_pair(new_evaluations[left_pos], new_evaluations[right_pos], assignment)
+                    })
+                    .collect();
+                new_evaluations[..folded.len()].copy_from_slice(&folded);
+                continue;
+            }
+
+            for (i, (left_pos, right_pos)) in pairs.into_iter().enumerate() {
+                new_evaluations[i] =
+                    fold_pair(new_evaluations[left_pos], new_evaluations[right_pos], assignment);
             }
         }
 
@@ -79,6 +136,22 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
         )?)
     }
 
+    /// Binds the trailing variables of the polynomial (the last
+    /// `assignments.len()` variables, which control the low-order bits of the
+    /// evaluation index since variable 0 is the top bit), without the caller
+    /// having to compute the starting variable for `partial_evaluate`
+    /// e.g. f(a, b, c, d, e, f).fix_last_variables([5, 6])
+    /// binds e = 5 and f = 6, returning a 4-variable polynomial over (a, b, c, d)
+    /// this lets sumcheck-style provers bind variables from the last-variable
+    /// end without reindexing the whole evaluation vector
+    pub fn fix_last_variables(&self, assignments: &[F]) -> Result<Self, &'static str> {
+        if assignments.len() > self.n_vars {
+            return Err("cannot fix more variables than the polynomial has");
+        }
+
+        self.partial_evaluate(self.n_vars - assignments.len(), assignments)
+    }
+
     /// Evaluate the `MultilinearPolynomial` at n points
     pub fn evaluate(&self, assignments: &[F]) -> Result<F, &'static str> {
         if assignments.len() != self.n_vars {
@@ -93,20 +166,121 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
         &self.evaluations
     }
 
-    /// Serialize the `MultilinearPolynomial`
+    /// Serialize the `MultilinearPolynomial` into a canonical, round-trippable
+    /// byte layout: an 8-byte big-endian `n_vars`, an 8-byte big-endian
+    /// per-element byte width, followed by each evaluation's big-endian
+    /// bytes. The header lets `from_bytes` reconstruct the polynomial without
+    /// any out-of-band knowledge of the variable count or field width.
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.evaluations
+        let element_bytes: Vec<Vec<u8>> = self
+            .evaluations
             .iter()
             .map(|elem| elem.into_bigint().to_bytes_be())
-            .collect::<Vec<Vec<u8>>>()
-            .concat()
+            .collect();
+        let element_width = element_bytes.first().map(Vec::len).unwrap_or(0);
+
+        let mut bytes = Vec::with_capacity(16 + element_bytes.len() * element_width);
+        bytes.extend_from_slice(&(self.n_vars as u64).to_be_bytes());
+        bytes.extend_from_slice(&(element_width as u64).to_be_bytes());
+        bytes.extend(element_bytes.concat());
+
+        bytes
+    }
+
+    /// Deserialize a `MultilinearPolynomial` previously produced by `to_bytes`,
+    /// validating that the evaluation count matches `2^n_vars`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        if bytes.len() < 16 {
+            return Err("byte buffer too short to contain a header");
+        }
+
+        let n_vars = u64::from_be_bytes(bytes[0..8].try_into().unwrap()) as usize;
+        let element_width = u64::from_be_bytes(bytes[8..16].try_into().unwrap()) as usize;
+
+        if element_width == 0 {
+            return Err("element width must be non-zero");
+        }
+
+        let hypercube_size = checked_hypercube_size(n_vars).ok_or("n_vars header is too large")?;
+        let expected_len = hypercube_size
+            .checked_mul(element_width)
+            .ok_or("n_vars/element width header overflows a byte length")?;
+        if bytes.len() - 16 != expected_len {
+            return Err("byte buffer length does not match n_vars and element width");
+        }
+
+        let evaluations = bytes[16..]
+            .chunks(element_width)
+            .map(F::from_be_bytes_mod_order)
+            .collect();
+
+        Self::new(n_vars, evaluations)
+    }
+}
+
+impl<F: PrimeField> CanonicalSerialize for MultiLinearPolynomial<F> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), ark_serialize::SerializationError> {
+        (self.n_vars as u64).serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        (self.n_vars as u64).serialized_size(compress) + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> ark_serialize::Valid for MultiLinearPolynomial<F> {
+    fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+        let hypercube_size = checked_hypercube_size(self.n_vars)
+            .ok_or(ark_serialize::SerializationError::InvalidData)?;
+        if self.evaluations.len() != hypercube_size {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
+        self.evaluations.check()
     }
 }
 
+impl<F: PrimeField> CanonicalDeserialize for MultiLinearPolynomial<F> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> Result<Self, ark_serialize::SerializationError> {
+        let n_vars = u64::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        let evaluations = Vec::<F>::deserialize_with_mode(&mut reader, compress, validate)?;
+
+        let hypercube_size = checked_hypercube_size(n_vars)
+            .ok_or(ark_serialize::SerializationError::InvalidData)?;
+        if evaluations.len() != hypercube_size {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
+
+        Ok(Self {
+            n_vars,
+            evaluations,
+        })
+    }
+}
+
+/// Computes `2^n_vars`, rejecting any `n_vars` too large for the shift to
+/// fit in a `usize` instead of panicking - `n_vars` here often comes
+/// straight off the wire (`from_bytes`/`CanonicalDeserialize`), so it must
+/// never be trusted enough to shift with directly.
+fn checked_hypercube_size(n_vars: usize) -> Option<usize> {
+    u32::try_from(n_vars)
+        .ok()
+        .and_then(|n_vars| 1usize.checked_shl(n_vars))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::multilinear::evaluation_form::MultiLinearPolynomial;
     use ark_bls12_381::Fr;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
     #[test]
     fn test_new_multilinear_poly() {
@@ -177,6 +351,132 @@ mod tests {
         // TODO: use the other polynomial representation to generate the evaluations
     }
 
+    #[test]
+    fn test_to_bytes_from_bytes_round_trip() {
+        let poly =
+            MultiLinearPolynomial::new(2, vec![Fr::from(3), Fr::from(1), Fr::from(2), Fr::from(5)])
+                .unwrap();
+
+        let bytes = poly.to_bytes();
+        let recovered = MultiLinearPolynomial::<Fr>::from_bytes(&bytes).unwrap();
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_mismatched_length() {
+        let poly =
+            MultiLinearPolynomial::new(2, vec![Fr::from(3), Fr::from(1), Fr::from(2), Fr::from(5)])
+                .unwrap();
+
+        let mut bytes = poly.to_bytes();
+        bytes.pop();
+        assert!(MultiLinearPolynomial::<Fr>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_huge_n_vars_header_instead_of_panicking() {
+        // n_vars = u64::MAX, element_width = 32, no payload bytes
+        let mut bytes = u64::MAX.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&32u64.to_be_bytes());
+        assert!(MultiLinearPolynomial::<Fr>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_canonical_deserialize_rejects_huge_n_vars_header_instead_of_panicking() {
+        // a canonically-serialized u64::MAX n_vars, followed by an empty vec length
+        let mut bytes = vec![];
+        u64::MAX
+            .serialize_with_mode(&mut bytes, ark_serialize::Compress::Yes)
+            .unwrap();
+        0u64.serialize_with_mode(&mut bytes, ark_serialize::Compress::Yes)
+            .unwrap();
+        assert!(MultiLinearPolynomial::<Fr>::deserialize_compressed(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_canonical_serialize_round_trip() {
+        let poly =
+            MultiLinearPolynomial::new(2, vec![Fr::from(3), Fr::from(1), Fr::from(2), Fr::from(5)])
+                .unwrap();
+
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).unwrap();
+        let recovered = MultiLinearPolynomial::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(recovered, poly);
+    }
+
+    #[test]
+    fn test_eq_mle_is_selector_on_hypercube() {
+        let r = [Fr::from(3), Fr::from(5)];
+        let eq = MultiLinearPolynomial::eq_mle(&r);
+        assert_eq!(eq.n_vars, 2);
+
+        // the identity sumcheck relies on: Sum_x eq(x, r) * f(x) over the
+        // boolean hypercube equals f(r), for any multilinear f. eq(x, r)
+        // only collapses to a 0/1 selector when x ranges over the boolean
+        // hypercube, so this - not eq(r, r) == 1 - is the property that
+        // actually matters
+        let f = MultiLinearPolynomial::new(2, vec![Fr::from(7), Fr::from(2), Fr::from(9), Fr::from(4)])
+            .unwrap();
+        let sum_over_hypercube: Fr = [Fr::from(0), Fr::from(1)]
+            .into_iter()
+            .flat_map(|a| [Fr::from(0), Fr::from(1)].map(|b| [a, b]))
+            .map(|point| eq.evaluate(&point).unwrap() * f.evaluate(&point).unwrap())
+            .sum();
+        assert_eq!(sum_over_hypercube, f.evaluate(&r).unwrap());
+
+        // on the boolean hypercube, eq(x, r) matches the eq formula directly
+        for a in [Fr::from(0), Fr::from(1)] {
+            for b in [Fr::from(0), Fr::from(1)] {
+                let expected = (a * r[0] + (Fr::from(1) - a) * (Fr::from(1) - r[0]))
+                    * (b * r[1] + (Fr::from(1) - b) * (Fr::from(1) - r[1]));
+                assert_eq!(eq.evaluate(&[a, b]).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fix_last_variables() {
+        // f(a, b, c) = 2ab + 3bc
+        let poly = MultiLinearPolynomial::new(
+            3,
+            vec![
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(3),
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(2),
+                Fr::from(5),
+            ],
+        )
+        .unwrap();
+
+        // binding c (the last variable) should match partial_evaluate starting there
+        let fixed = poly.fix_last_variables(&[Fr::from(4)]).unwrap();
+        let expected = poly.partial_evaluate(2, &[Fr::from(4)]).unwrap();
+        assert_eq!(fixed, expected);
+
+        // binding b, c (the last two variables) should match full evaluation
+        // when a is then bound too
+        let fixed = poly.fix_last_variables(&[Fr::from(2), Fr::from(3)]).unwrap();
+        assert_eq!(fixed.n_vars, 1);
+        assert_eq!(
+            fixed.evaluate(&[Fr::from(5)]).unwrap(),
+            poly.evaluate(&[Fr::from(5), Fr::from(2), Fr::from(3)])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fix_last_variables_rejects_too_many_assignments() {
+        let poly = MultiLinearPolynomial::new(1, vec![Fr::from(3), Fr::from(1)]).unwrap();
+        assert!(poly
+            .fix_last_variables(&[Fr::from(1), Fr::from(2)])
+            .is_err());
+    }
+
     #[test]
     fn test_full_evaluation() {
         // f(a, b, c) = 2ab + 3bc
@@ -200,4 +500,41 @@ mod tests {
             .unwrap();
         assert_eq!(evaluation_result, Fr::from(48));
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_partial_evaluate_matches_brute_force_with_the_parallel_fold_enabled() {
+        // 12 variables puts the first round's pair count (2^11 = 2048) above
+        // PARALLEL_FOLD_THRESHOLD, so this actually exercises the rayon fold
+        // path instead of silently falling back to the sequential one
+        let n_vars = 12;
+        let evaluations: Vec<Fr> = (0..(1u64 << n_vars)).map(Fr::from).collect();
+        let poly = MultiLinearPolynomial::new(n_vars, evaluations.clone()).unwrap();
+
+        let assignments: Vec<Fr> = (0..n_vars as u64).map(|i| Fr::from(i + 2)).collect();
+
+        // brute-force multilinear extension formula, independent of
+        // partial_evaluate's fold-pair machinery: f(r) = sum_x eq(x, r) * f(x)
+        let expected: Fr = evaluations
+            .iter()
+            .enumerate()
+            .map(|(x, f_x)| {
+                let weight: Fr = assignments
+                    .iter()
+                    .enumerate()
+                    .map(|(i, r_i)| {
+                        let bit = (x >> (n_vars - 1 - i)) & 1;
+                        if bit == 1 {
+                            *r_i
+                        } else {
+                            Fr::from(1) - r_i
+                        }
+                    })
+                    .product();
+                weight * f_x
+            })
+            .sum();
+
+        assert_eq!(poly.evaluate(&assignments).unwrap(), expected);
+    }
 }
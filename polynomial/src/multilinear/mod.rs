@@ -0,0 +1,4 @@
+pub mod evaluation_form;
+pub mod pairing_index;
+pub mod sparse;
+pub mod virtual_poly;
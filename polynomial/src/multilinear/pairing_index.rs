@@ -0,0 +1,55 @@
+/// Returns the `(left, right)` evaluation-vector index pairs for folding
+/// variable `var` out of an `n_vars`-bit boolean hypercube index, where
+/// variable bits are read MSB-first (variable `0` is the top bit).
+///
+/// For each of the `2^(n_vars - 1)` remaining assignments to the other
+/// variables, `left` is the index with `var` fixed to `0` and `right` is
+/// the same index with `var` fixed to `1` - `partial_evaluate` reads both
+/// out of the current evaluation vector and folds them into one output
+/// slot per pair, one slot per call to `assignment`.
+pub fn index_pair(n_vars: u8, var: u8) -> impl Iterator<Item = (usize, usize)> {
+    let n_vars = n_vars as usize;
+    let var = var as usize;
+    let low_bits = n_vars - 1 - var;
+    let low_mask = (1usize << low_bits) - 1;
+
+    (0..(1usize << (n_vars - 1))).map(move |i| {
+        let high = i >> low_bits;
+        let low = i & low_mask;
+        let left = (high << (low_bits + 1)) | low;
+        let right = left | (1 << low_bits);
+        (left, right)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::index_pair;
+
+    #[test]
+    fn test_index_pair_folds_the_top_variable() {
+        // n_vars = 2, var = 0 (the top bit): pairs split the vector in half
+        assert_eq!(index_pair(2, 0).collect::<Vec<_>>(), vec![(0, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn test_index_pair_folds_an_interior_variable() {
+        // f(a, b, c), var = 1 (b): pairs hold a and c fixed, vary b
+        assert_eq!(
+            index_pair(3, 1).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 3), (4, 6), (5, 7)]
+        );
+    }
+
+    #[test]
+    fn test_index_pair_covers_every_index_exactly_once() {
+        let n_vars = 5;
+        let mut seen = vec![false; 1 << n_vars];
+        for (left, right) in index_pair(n_vars, 2) {
+            assert!(!seen[left] && !seen[right], "index visited twice");
+            seen[left] = true;
+            seen[right] = true;
+        }
+        assert!(seen.into_iter().all(|visited| visited));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::multilinear::evaluation_form::MultiLinearPolynomial;
+use ark_ff::PrimeField;
+
+/// `SparseMultilinearPolynomial` holds only the nonzero evaluations over the
+/// boolean hypercube of an `n_vars` multilinear polynomial, as `(index, value)`
+/// pairs. This is a much cheaper representation than the dense form for
+/// structured polynomials (wiring predicates, selectors) that only take
+/// nonzero values on a small fraction of the hypercube.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMultilinearPolynomial<F: PrimeField> {
+    n_vars: usize,
+    evaluations: Vec<(usize, F)>,
+}
+
+impl<F: PrimeField> SparseMultilinearPolynomial<F> {
+    /// Instantiates a new `SparseMultilinearPolynomial` after ensuring every
+    /// stored index fits within `2^n_vars`
+    pub fn new(n_vars: usize, evaluations: Vec<(usize, F)>) -> Result<Self, &'static str> {
+        if evaluations.iter().any(|(index, _)| *index >= (1 << n_vars)) {
+            return Err("evaluation index out of bounds for n_vars");
+        }
+
+        Ok(Self {
+            n_vars,
+            evaluations,
+        })
+    }
+
+    /// Returns the number of variables
+    pub fn n_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    /// Returns the stored (index, value) pairs
+    pub fn evaluations(&self) -> &[(usize, F)] {
+        &self.evaluations
+    }
+
+    /// Evaluate the `SparseMultilinearPolynomial` at n points
+    /// for each stored (index, value), accumulates
+    /// value * ∏_i (index_bit_i ? r_i : 1-r_i), skipping zero entries entirely
+    pub fn evaluate(&self, assignments: &[F]) -> Result<F, &'static str> {
+        if assignments.len() != self.n_vars {
+            return Err("evaluate must assign to all variables");
+        }
+
+        Ok(self
+            .evaluations
+            .iter()
+            .map(|(index, value)| *value * eq_weight(*index, self.n_vars, assignments))
+            .sum())
+    }
+
+    /// Partially evaluate the `SparseMultilinearPolynomial` at n consecutive
+    /// variables, matching the dense `MultiLinearPolynomial::partial_evaluate`
+    /// convention. Entries that are never stored contribute zero and are
+    /// skipped, rather than being materialized.
+    pub fn partial_evaluate(
+        &self,
+        initial_var: usize,
+        assignments: &[F],
+    ) -> Result<Self, &'static str> {
+        if initial_var + assignments.len() > self.n_vars {
+            return Err("partial_evaluate range exceeds n_vars");
+        }
+
+        let new_n_vars = self.n_vars - assignments.len();
+        let mut folded = std::collections::HashMap::new();
+
+        for (index, value) in &self.evaluations {
+            let remaining_index = remove_bits(*index, self.n_vars, initial_var, assignments.len());
+            let pattern = middle_bits(*index, self.n_vars, initial_var, assignments.len());
+            let weight = pattern_weight(pattern, assignments);
+            if weight.is_zero() {
+                continue;
+            }
+
+            *folded.entry(remaining_index).or_insert(F::zero()) += *value * weight;
+        }
+
+        let evaluations = folded
+            .into_iter()
+            .filter(|(_, value)| !value.is_zero())
+            .collect();
+
+        Self::new(new_n_vars, evaluations)
+    }
+
+    /// Converts this sparse polynomial into its dense evaluation form
+    pub fn to_dense(&self) -> Result<MultiLinearPolynomial<F>, &'static str> {
+        let mut dense = vec![F::zero(); 1 << self.n_vars];
+        for (index, value) in &self.evaluations {
+            dense[*index] = *value;
+        }
+        MultiLinearPolynomial::new(self.n_vars, dense)
+    }
+
+    /// Builds a sparse polynomial from a dense one, dropping zero evaluations
+    pub fn from_dense(dense: &MultiLinearPolynomial<F>) -> Self {
+        let evaluations = dense
+            .evaluation_slice()
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !value.is_zero())
+            .map(|(index, value)| (index, *value))
+            .collect();
+
+        Self {
+            n_vars: dense.n_vars(),
+            evaluations,
+        }
+    }
+}
+
+/// Computes ∏_i (index_bit_i ? r_i : 1-r_i) where `index`'s bits are read
+/// MSB-first, matching `MultiLinearPolynomial::eq_mle`'s variable ordering
+fn eq_weight<F: PrimeField>(index: usize, n_vars: usize, r: &[F]) -> F {
+    let mut weight = F::one();
+    for (i, r_i) in r.iter().enumerate() {
+        let bit = (index >> (n_vars - 1 - i)) & 1;
+        weight *= if bit == 1 { *r_i } else { F::one() - r_i };
+    }
+    weight
+}
+
+/// Computes ∏_i (pattern_bit_i ? assignments_i : 1-assignments_i), where
+/// `pattern`'s bits are read MSB-first over `assignments.len()` bits
+fn pattern_weight<F: PrimeField>(pattern: usize, assignments: &[F]) -> F {
+    let mut weight = F::one();
+    for (i, assignment) in assignments.iter().enumerate() {
+        let bit = (pattern >> (assignments.len() - 1 - i)) & 1;
+        weight *= if bit == 1 { *assignment } else { F::one() - assignment };
+    }
+    weight
+}
+
+/// Removes the `length` bits starting at variable `start` from `index`,
+/// closing the gap so the remaining bits form a contiguous `n_vars - length`
+/// bit index over the untouched variables
+fn remove_bits(index: usize, n_vars: usize, start: usize, length: usize) -> usize {
+    let high_bits = index >> (n_vars - start);
+    let low_mask = (1usize << (n_vars - start - length)) - 1;
+    let low_bits = index & low_mask;
+    (high_bits << (n_vars - start - length)) | low_bits
+}
+
+/// Extracts the `length` bits starting at variable `start` from `index`
+fn middle_bits(index: usize, n_vars: usize, start: usize, length: usize) -> usize {
+    (index >> (n_vars - start - length)) & ((1 << length) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseMultilinearPolynomial;
+    use crate::multilinear::evaluation_form::MultiLinearPolynomial;
+    use ark_bls12_381::Fr;
+
+    fn dense_poly() -> MultiLinearPolynomial<Fr> {
+        // f(a, b, c) = 2ab + 3bc
+        MultiLinearPolynomial::new(
+            3,
+            vec![
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(3),
+                Fr::from(0),
+                Fr::from(0),
+                Fr::from(2),
+                Fr::from(5),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_out_of_bounds_index() {
+        assert!(SparseMultilinearPolynomial::<Fr>::new(2, vec![(4, Fr::from(1))]).is_err());
+        assert!(SparseMultilinearPolynomial::<Fr>::new(2, vec![(3, Fr::from(1))]).is_ok());
+    }
+
+    #[test]
+    fn test_from_dense_and_to_dense_round_trip() {
+        let dense = dense_poly();
+        let sparse = SparseMultilinearPolynomial::from_dense(&dense);
+        // only the 3 nonzero corners (011, 110, 111) should be stored
+        assert_eq!(sparse.evaluations().len(), 3);
+        assert_eq!(sparse.to_dense().unwrap(), dense);
+    }
+
+    #[test]
+    fn test_evaluate_matches_dense() {
+        let dense = dense_poly();
+        let sparse = SparseMultilinearPolynomial::from_dense(&dense);
+
+        let point = [Fr::from(2), Fr::from(3), Fr::from(4)];
+        assert_eq!(
+            sparse.evaluate(&point).unwrap(),
+            dense.evaluate(&point).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_partial_evaluate_matches_dense() {
+        let dense = dense_poly();
+        let sparse = SparseMultilinearPolynomial::from_dense(&dense);
+
+        let folded_sparse = sparse.partial_evaluate(1, &[Fr::from(2), Fr::from(3)]).unwrap();
+        let folded_dense = dense.partial_evaluate(1, &[Fr::from(2), Fr::from(3)]).unwrap();
+
+        assert_eq!(folded_sparse.to_dense().unwrap(), folded_dense);
+    }
+}
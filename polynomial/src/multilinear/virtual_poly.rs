@@ -0,0 +1,248 @@
+use crate::multilinear::evaluation_form::MultiLinearPolynomial;
+use ark_ff::PrimeField;
+
+/// `VirtualPolynomial` represents a sum of products of multilinear extensions
+/// e.g. c0*f0*f1*f2 + c1*f3*f4
+/// rather than materializing this expression into a single dense MLE, it keeps
+/// a flattened list of the underlying MLEs (all sharing the same `n_vars`) and,
+/// for each product term, the coefficient plus the indices of the MLEs that
+/// make up that term. This is the shape sumcheck/GKR provers iterate over
+/// round by round.
+#[derive(Clone, Debug)]
+pub struct VirtualPolynomial<F: PrimeField> {
+    n_vars: usize,
+    mles: Vec<MultiLinearPolynomial<F>>,
+    products: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: PrimeField> VirtualPolynomial<F> {
+    /// Instantiates an empty `VirtualPolynomial` over `n_vars` variables
+    pub fn new(n_vars: usize) -> Self {
+        Self {
+            n_vars,
+            mles: vec![],
+            products: vec![],
+        }
+    }
+
+    /// Returns the number of variables
+    pub fn n_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    /// Adds the product `coefficient * mles[0] * mles[1] * ...` to the sum
+    /// all supplied mles must share this `VirtualPolynomial`'s `n_vars`
+    pub fn add_mle_list(
+        &mut self,
+        coefficient: F,
+        mles: Vec<MultiLinearPolynomial<F>>,
+    ) -> Result<(), &'static str> {
+        if mles.is_empty() {
+            return Err("product must contain at least one mle");
+        }
+
+        if mles.iter().any(|mle| mle.n_vars() != self.n_vars) {
+            return Err("mle n_vars must match virtual polynomial n_vars");
+        }
+
+        let mut indices = Vec::with_capacity(mles.len());
+        for mle in mles {
+            indices.push(self.mles.len());
+            self.mles.push(mle);
+        }
+
+        self.products.push((coefficient, indices));
+
+        Ok(())
+    }
+
+    /// Multiplies every existing product term by `mle`, scaling each
+    /// product's coefficient by `coefficient`
+    /// e.g. if the sum is c0*f0*f1 + c1*f2, calling mul_by_mle(f3, d)
+    /// turns it into d*c0*f0*f1*f3 + d*c1*f2*f3
+    pub fn mul_by_mle(&mut self, mle: MultiLinearPolynomial<F>, coefficient: F) -> Result<(), &'static str> {
+        if mle.n_vars() != self.n_vars {
+            return Err("mle n_vars must match virtual polynomial n_vars");
+        }
+
+        let mle_index = self.mles.len();
+        self.mles.push(mle);
+
+        for (coeff, indices) in self.products.iter_mut() {
+            indices.push(mle_index);
+            *coeff *= coefficient;
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the virtual polynomial at `point` by evaluating every
+    /// underlying mle once, then combining the per-product factors
+    pub fn evaluate(&self, point: &[F]) -> Result<F, &'static str> {
+        if point.len() != self.n_vars {
+            return Err("point length must match n_vars");
+        }
+
+        let mle_evaluations = self
+            .mles
+            .iter()
+            .map(|mle| mle.evaluate(point))
+            .collect::<Result<Vec<F>, _>>()?;
+
+        Ok(self
+            .products
+            .iter()
+            .map(|(coefficient, indices)| {
+                *coefficient * indices.iter().map(|&i| mle_evaluations[i]).product::<F>()
+            })
+            .sum())
+    }
+
+    /// Binds the first (lowest-index) variable of every underlying mle to
+    /// `assignment`, returning a new `VirtualPolynomial` over `n_vars - 1`
+    /// variables with the same product structure. This is the per-round
+    /// folding step the sumcheck prover uses to shrink the claim.
+    pub fn fix_first_variable(&self, assignment: F) -> Result<Self, &'static str> {
+        if self.n_vars == 0 {
+            return Err("cannot fix a variable on a 0-variable polynomial");
+        }
+
+        let mles = self
+            .mles
+            .iter()
+            .map(|mle| mle.partial_evaluate(0, &[assignment]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            n_vars: self.n_vars - 1,
+            mles,
+            products: self.products.clone(),
+        })
+    }
+
+    /// Returns the largest product length across all terms
+    /// (the degree sumcheck round polynomials need to be sized for)
+    pub fn max_degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Sums the virtual polynomial's value over every point of the boolean
+    /// hypercube, reading each underlying mle's already-folded dense
+    /// evaluation vector directly instead of calling `evaluate` per corner.
+    /// This is what lets a sumcheck prover derive a round polynomial in
+    /// `O(2^n_vars)` instead of `O(4^n_vars)`: the per-mle fold already did
+    /// the expensive part, this just walks the resulting evaluation slices.
+    pub fn sum_over_hypercube(&self) -> F {
+        let hypercube_size = 1usize << self.n_vars;
+
+        (0..hypercube_size)
+            .map(|i| {
+                self.products
+                    .iter()
+                    .map(|(coefficient, indices)| {
+                        *coefficient
+                            * indices
+                                .iter()
+                                .map(|&mle_index| self.mles[mle_index].evaluation_slice()[i])
+                                .product::<F>()
+                    })
+                    .sum::<F>()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VirtualPolynomial;
+    use crate::multilinear::evaluation_form::MultiLinearPolynomial;
+    use ark_bls12_381::Fr;
+
+    fn mle_a() -> MultiLinearPolynomial<Fr> {
+        // f(a, b) = a
+        MultiLinearPolynomial::new(2, vec![Fr::from(0), Fr::from(0), Fr::from(1), Fr::from(1)])
+            .unwrap()
+    }
+
+    fn mle_b() -> MultiLinearPolynomial<Fr> {
+        // f(a, b) = b
+        MultiLinearPolynomial::new(2, vec![Fr::from(0), Fr::from(1), Fr::from(0), Fr::from(1)])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add_mle_list_rejects_mismatched_n_vars() {
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        let mismatched = MultiLinearPolynomial::new(1, vec![Fr::from(0), Fr::from(1)]).unwrap();
+        assert!(poly.add_mle_list(Fr::from(1), vec![mismatched]).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_sum_of_products() {
+        // 2ab + 3a, evaluated at a = 2, b = 3 => 2*2*3 + 3*2 = 18
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        poly.add_mle_list(Fr::from(2), vec![mle_a(), mle_b()])
+            .unwrap();
+        poly.add_mle_list(Fr::from(3), vec![mle_a()]).unwrap();
+
+        let result = poly.evaluate(&[Fr::from(2), Fr::from(3)]).unwrap();
+        assert_eq!(result, Fr::from(18));
+    }
+
+    #[test]
+    fn test_max_degree() {
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        poly.add_mle_list(Fr::from(1), vec![mle_a()]).unwrap();
+        poly.add_mle_list(Fr::from(1), vec![mle_a(), mle_b()])
+            .unwrap();
+        assert_eq!(poly.max_degree(), 2);
+    }
+
+    #[test]
+    fn test_mul_by_mle_extends_every_product() {
+        // start with a, then multiply everything by b with coefficient 2 -> 2ab
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        poly.add_mle_list(Fr::from(1), vec![mle_a()]).unwrap();
+        poly.mul_by_mle(mle_b(), Fr::from(2)).unwrap();
+
+        let result = poly.evaluate(&[Fr::from(2), Fr::from(3)]).unwrap();
+        assert_eq!(result, Fr::from(2) * Fr::from(2) * Fr::from(3));
+    }
+
+    #[test]
+    fn test_fix_first_variable_matches_evaluate() {
+        // 2ab, fixing a = 5 should leave 10b, matching a direct evaluation at a = 5
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        poly.add_mle_list(Fr::from(2), vec![mle_a(), mle_b()])
+            .unwrap();
+
+        let folded = poly.fix_first_variable(Fr::from(5)).unwrap();
+        assert_eq!(folded.n_vars(), 1);
+        assert_eq!(
+            folded.evaluate(&[Fr::from(3)]).unwrap(),
+            poly.evaluate(&[Fr::from(5), Fr::from(3)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sum_over_hypercube_matches_brute_force_sum() {
+        // 2ab + 3a, summed over every corner of {0,1}^2
+        let mut poly = VirtualPolynomial::<Fr>::new(2);
+        poly.add_mle_list(Fr::from(2), vec![mle_a(), mle_b()])
+            .unwrap();
+        poly.add_mle_list(Fr::from(3), vec![mle_a()]).unwrap();
+
+        let brute_force_sum: Fr = (0u64..4)
+            .map(|i| {
+                let point = [Fr::from((i >> 1) & 1), Fr::from(i & 1)];
+                poly.evaluate(&point).unwrap()
+            })
+            .sum();
+
+        assert_eq!(poly.sum_over_hypercube(), brute_force_sum);
+    }
+}
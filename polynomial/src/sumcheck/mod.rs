@@ -0,0 +1,5 @@
+mod prover;
+mod verifier;
+
+pub use prover::{RoundPolynomial, SumcheckProver};
+pub use verifier::Verifier;
@@ -0,0 +1,144 @@
+use crate::multilinear::virtual_poly::VirtualPolynomial;
+use ark_ff::PrimeField;
+use stat::{end_timer, start_timer};
+
+/// A round's univariate polynomial, represented by its evaluations at
+/// `0, 1, ..., degree` rather than coefficients - that's all a verifier
+/// needs to check `g(0) + g(1) == previous_claim` and to interpolate the
+/// claim at a random challenge point
+pub type RoundPolynomial<F> = Vec<F>;
+
+/// Drives the prover side of the interactive sumcheck protocol over a
+/// `VirtualPolynomial`, folding one variable per round
+pub struct SumcheckProver<F: PrimeField> {
+    poly: VirtualPolynomial<F>,
+}
+
+impl<F: PrimeField> SumcheckProver<F> {
+    pub fn new(poly: VirtualPolynomial<F>) -> Self {
+        Self { poly }
+    }
+
+    /// Produces this round's univariate polynomial by fixing the current
+    /// lowest-index unbound variable to each of `0..=degree` and summing the
+    /// folded polynomial's evaluations over the remaining boolean hypercube.
+    /// Each fold reads straight from the already-folded mle evaluation
+    /// vectors (`VirtualPolynomial::sum_over_hypercube`) rather than
+    /// re-evaluating every corner from scratch, which is what keeps this
+    /// tractable past toy variable counts.
+    pub fn round_polynomial(&self) -> RoundPolynomial<F> {
+        let degree = self.poly.max_degree();
+
+        (0..=degree)
+            .map(|point| {
+                self.poly
+                    .fix_first_variable(F::from(point as u64))
+                    .expect("the prover always has at least one unbound variable mid-round")
+                    .sum_over_hypercube()
+            })
+            .collect()
+    }
+
+    /// Binds the current lowest-index variable to `challenge`, shrinking the
+    /// underlying virtual polynomial by one variable ahead of the next round
+    pub fn receive_challenge(&mut self, challenge: F) -> Result<(), &'static str> {
+        self.poly = self.poly.fix_first_variable(challenge)?;
+        Ok(())
+    }
+
+    /// Runs every round of the protocol against `verifier`, using `rng` to
+    /// supply the verifier's randomness, and returns the virtual
+    /// polynomial's evaluation at the sampled challenge point - the claim
+    /// the verifier checks the protocol transcript reduces to
+    pub fn prove<R: ark_std::rand::RngCore>(
+        mut self,
+        verifier: &mut super::verifier::Verifier<F>,
+        rng: &mut R,
+    ) -> Result<F, &'static str> {
+        let n_vars = self.poly.n_vars();
+
+        for _ in 0..n_vars {
+            start_timer!("sumcheck round");
+            let round_poly = self.round_polynomial();
+            let challenge = verifier.check_round(&round_poly, rng)?;
+            self.receive_challenge(challenge)?;
+            end_timer!();
+        }
+
+        self.poly.evaluate(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SumcheckProver;
+    use crate::multilinear::evaluation_form::MultiLinearPolynomial;
+    use crate::multilinear::virtual_poly::VirtualPolynomial;
+    use crate::sumcheck::verifier::Verifier;
+    use ark_bls12_381::Fr;
+    use ark_std::test_rng;
+
+    fn claimed_sum_over_hypercube<F: ark_ff::PrimeField>(poly: &VirtualPolynomial<F>) -> F {
+        (0..(1u64 << poly.n_vars()))
+            .map(|i| {
+                let assignment: Vec<F> = (0..poly.n_vars())
+                    .map(|bit| F::from((i >> (poly.n_vars() - 1 - bit)) & 1))
+                    .collect();
+                poly.evaluate(&assignment).unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_full_sumcheck_protocol_accepts_honest_prover() {
+        // f(a, b) = 2ab + 3a, claimed sum over the hypercube
+        let a = MultiLinearPolynomial::new(2, vec![Fr::from(0), Fr::from(0), Fr::from(1), Fr::from(1)])
+            .unwrap();
+        let b = MultiLinearPolynomial::new(2, vec![Fr::from(0), Fr::from(1), Fr::from(0), Fr::from(1)])
+            .unwrap();
+
+        let mut poly = VirtualPolynomial::new(2);
+        poly.add_mle_list(Fr::from(2), vec![a.clone(), b]).unwrap();
+        poly.add_mle_list(Fr::from(3), vec![a]).unwrap();
+
+        let claimed_sum = claimed_sum_over_hypercube(&poly);
+
+        let mut verifier = Verifier::new(claimed_sum);
+        let mut rng = test_rng();
+        let final_evaluation = SumcheckProver::new(poly).prove(&mut verifier, &mut rng).unwrap();
+
+        assert!(verifier.verify_final_evaluation(final_evaluation).is_ok());
+    }
+
+    fn var_mle(n_vars: usize, var_index: usize) -> MultiLinearPolynomial<Fr> {
+        let evaluations = (0..(1usize << n_vars))
+            .map(|index| Fr::from(((index >> (n_vars - 1 - var_index)) & 1) as u64))
+            .collect();
+        MultiLinearPolynomial::new(n_vars, evaluations).unwrap()
+    }
+
+    #[test]
+    fn test_full_sumcheck_protocol_with_a_degree_three_product_over_more_variables() {
+        // 5*x0*x1*x2 + 2*x3, over 6 variables - large enough that the naive
+        // O(4^n_vars) per-round evaluation would be dramatically slower
+        // than this should be, and catches correctness regressions a
+        // 2-variable test can't
+        let n_vars = 6;
+        let mut poly = VirtualPolynomial::new(n_vars);
+        poly.add_mle_list(
+            Fr::from(5),
+            vec![var_mle(n_vars, 0), var_mle(n_vars, 1), var_mle(n_vars, 2)],
+        )
+        .unwrap();
+        poly.add_mle_list(Fr::from(2), vec![var_mle(n_vars, 3)])
+            .unwrap();
+
+        let claimed_sum = claimed_sum_over_hypercube(&poly);
+
+        let mut verifier = Verifier::new(claimed_sum);
+        let mut rng = test_rng();
+        let final_evaluation = SumcheckProver::new(poly).prove(&mut verifier, &mut rng).unwrap();
+
+        assert!(verifier.verify_final_evaluation(final_evaluation).is_ok());
+    }
+}
@@ -0,0 +1,106 @@
+use super::prover::RoundPolynomial;
+use ark_ff::PrimeField;
+use ark_std::rand::RngCore;
+
+/// Drives the verifier side of the interactive sumcheck protocol: checks
+/// each round polynomial against the running claim, samples a random
+/// challenge, and reduces the claim for the next round
+pub struct Verifier<F: PrimeField> {
+    expected_claim: F,
+    challenges: Vec<F>,
+}
+
+impl<F: PrimeField> Verifier<F> {
+    pub fn new(claimed_sum: F) -> Self {
+        Self {
+            expected_claim: claimed_sum,
+            challenges: vec![],
+        }
+    }
+
+    /// Checks `round_poly(0) + round_poly(1) == expected_claim`, samples a
+    /// fresh challenge with `rng`, reduces the running claim to this
+    /// round's polynomial evaluated at that challenge, and returns the
+    /// challenge to send back to the prover
+    pub fn check_round<R: RngCore>(
+        &mut self,
+        round_poly: &RoundPolynomial<F>,
+        rng: &mut R,
+    ) -> Result<F, &'static str> {
+        if round_poly.len() < 2 {
+            return Err("round polynomial must be evaluated at at least 2 points");
+        }
+
+        if round_poly[0] + round_poly[1] != self.expected_claim {
+            return Err("round polynomial is inconsistent with the previous claim");
+        }
+
+        let challenge = F::rand(rng);
+        self.expected_claim = interpolate_and_evaluate(round_poly, challenge);
+        self.challenges.push(challenge);
+
+        Ok(challenge)
+    }
+
+    /// Returns the challenges sampled so far, in round order
+    pub fn challenges(&self) -> &[F] {
+        &self.challenges
+    }
+
+    /// Confirms the claim left over after the final round matches the
+    /// prover's evaluation of the (fully bound) virtual polynomial -
+    /// reducing the original hypercube sum to this single point check
+    pub fn verify_final_evaluation(&self, final_evaluation: F) -> Result<(), &'static str> {
+        if self.expected_claim != final_evaluation {
+            return Err("final round claim does not match the oracle evaluation");
+        }
+
+        Ok(())
+    }
+}
+
+/// Lagrange-interpolates the univariate polynomial through its evaluations
+/// at `0, 1, ..., evaluations.len() - 1` and evaluates the result at `point`
+fn interpolate_and_evaluate<F: PrimeField>(evaluations: &[F], point: F) -> F {
+    let mut result = F::zero();
+
+    for (i, y_i) in evaluations.iter().enumerate() {
+        let mut term = *y_i;
+        for j in 0..evaluations.len() {
+            if i == j {
+                continue;
+            }
+            let x_i = F::from(i as u64);
+            let x_j = F::from(j as u64);
+            term *= (point - x_j) / (x_i - x_j);
+        }
+        result += term;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interpolate_and_evaluate, Verifier};
+    use ark_bls12_381::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_interpolate_and_evaluate_matches_known_points() {
+        // g(x) = x^2, sampled at 0, 1, 2
+        let evaluations = vec![Fr::from(0), Fr::from(1), Fr::from(4)];
+        assert_eq!(interpolate_and_evaluate(&evaluations, Fr::from(0)), Fr::from(0));
+        assert_eq!(interpolate_and_evaluate(&evaluations, Fr::from(1)), Fr::from(1));
+        assert_eq!(interpolate_and_evaluate(&evaluations, Fr::from(3)), Fr::from(9));
+    }
+
+    #[test]
+    fn test_check_round_rejects_inconsistent_polynomial() {
+        let mut verifier = Verifier::new(Fr::from(10));
+        let mut rng = test_rng();
+        // g(0) + g(1) = 1 + 2 = 3, inconsistent with the claimed sum of 10
+        let round_poly = vec![Fr::from(1), Fr::from(2)];
+        assert!(verifier.check_round(&round_poly, &mut rng).is_err());
+    }
+}
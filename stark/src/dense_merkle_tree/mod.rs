@@ -0,0 +1,4 @@
+pub mod tree;
+pub mod util;
+
+pub use tree::{verify, Hash, MerkleTree};
@@ -0,0 +1,152 @@
+use crate::dense_merkle_tree::util::{
+    extend_to_power_of_two, extra_hash_count, number_of_leaves, parent, sibling,
+};
+use ark_ff::{BigInteger, PrimeField};
+use polynomial::multilinear::evaluation_form::MultiLinearPolynomial;
+use sha2::{Digest, Sha256};
+
+/// A commitment to a single tree node
+pub type Hash = [u8; 32];
+
+/// `MerkleTree` commits to a `MultiLinearPolynomial`'s evaluation vector,
+/// padding it to a power of two, and supports opening individual
+/// evaluations with a proof that can be checked against the root without
+/// access to the rest of the tree.
+///
+/// Nodes are stored level-order in a single flat array (root at index 0,
+/// node `i`'s children at `2i+1`/`2i+2`), the same layout `sibling`/`parent`
+/// already assume.
+pub struct MerkleTree<F: PrimeField> {
+    values: Vec<F>,
+    nodes: Vec<Hash>,
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    /// Commits to `poly`'s evaluation vector, padding with `F::zero()` up to
+    /// the next power of two, and returns the tree alongside its root
+    pub fn commit(poly: &MultiLinearPolynomial<F>) -> (Self, Hash) {
+        let mut values = poly.evaluation_slice().to_vec();
+        extend_to_power_of_two(&mut values, F::zero());
+
+        let leaf_count = values.len();
+        let total = leaf_count + extra_hash_count(leaf_count);
+        let mut nodes = vec![Hash::default(); total];
+
+        for (i, value) in values.iter().enumerate() {
+            nodes[leaf_count - 1 + i] = hash_leaf(value);
+        }
+
+        // internal nodes close over their children, so build from the
+        // deepest level up to the root
+        for i in (0..leaf_count - 1).rev() {
+            nodes[i] = hash_pair(&nodes[2 * i + 1], &nodes[2 * i + 2]);
+        }
+
+        let root = nodes[0];
+        (Self { values, nodes }, root)
+    }
+
+    /// Opens the evaluation at `index`, returning its value and the sibling
+    /// hashes along the path from that leaf up to (but excluding) the root
+    pub fn open(&self, index: usize) -> Result<(F, Vec<Hash>), &'static str> {
+        let leaf_count = number_of_leaves(self.nodes.len());
+        if index >= leaf_count {
+            return Err("leaf index out of bounds");
+        }
+
+        let mut path = vec![];
+        let mut node_index = leaf_count - 1 + index;
+        while node_index != 0 {
+            path.push(self.nodes[sibling(node_index)]);
+            node_index = parent(node_index);
+        }
+
+        Ok((self.values[index], path))
+    }
+}
+
+/// Recomputes the root from `value` and its opening `path`, returning
+/// whether it matches `root`
+pub fn verify<F: PrimeField>(root: Hash, index: usize, value: F, path: &[Hash]) -> bool {
+    let mut node_index = (1usize << path.len()) - 1 + index;
+    let mut current_hash = hash_leaf(&value);
+
+    for sibling_hash in path {
+        current_hash = if node_index.is_multiple_of(2) {
+            // even index: this node is a right child, its sibling is to the left
+            hash_pair(sibling_hash, &current_hash)
+        } else {
+            hash_pair(&current_hash, sibling_hash)
+        };
+        node_index = parent(node_index);
+    }
+
+    current_hash == root
+}
+
+fn hash_leaf<F: PrimeField>(value: &F) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(value.into_bigint().to_bytes_be());
+    let digest = hasher.finalize();
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&digest);
+    hash
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    let digest = hasher.finalize();
+
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&digest);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, MerkleTree};
+    use ark_bls12_381::Fr;
+    use polynomial::multilinear::evaluation_form::MultiLinearPolynomial;
+
+    fn poly() -> MultiLinearPolynomial<Fr> {
+        // an arbitrary 2-variable polynomial, already a power-of-two number of evaluations
+        MultiLinearPolynomial::new(2, vec![Fr::from(3), Fr::from(1), Fr::from(2), Fr::from(5)])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_open_then_verify_succeeds_for_every_leaf() {
+        let (tree, root) = MerkleTree::commit(&poly());
+
+        for index in 0..4 {
+            let (value, path) = tree.open(index).unwrap();
+            assert!(verify(root, index, value, &path));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_value() {
+        let (tree, root) = MerkleTree::commit(&poly());
+        let (_, path) = tree.open(1).unwrap();
+        assert!(!verify(root, 1, Fr::from(999), &path));
+    }
+
+    #[test]
+    fn test_open_rejects_out_of_bounds_index() {
+        let (tree, _) = MerkleTree::commit(&poly());
+        assert!(tree.open(4).is_err());
+    }
+
+    #[test]
+    fn test_commit_single_level_tree() {
+        let poly = MultiLinearPolynomial::new(1, vec![Fr::from(1), Fr::from(2)]).unwrap();
+        let (tree, root) = MerkleTree::commit(&poly);
+        let (value, path) = tree.open(0).unwrap();
+        assert_eq!(value, Fr::from(1));
+        assert_eq!(path.len(), 1);
+        assert!(verify(root, 0, value, &path));
+    }
+}
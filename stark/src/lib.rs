@@ -0,0 +1 @@
+pub mod dense_merkle_tree;